@@ -0,0 +1,282 @@
+use crate::store::Store;
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::time::{Duration, Instant};
+
+/// How long after our own write to ignore filesystem-change notifications
+/// for the same file, so auto-save doesn't trigger a reload storm.
+const OWN_WRITE_GRACE_PERIOD: Duration = Duration::from_millis(500);
+
+#[derive(Clone, Serialize, Deserialize)]
+pub(crate) struct Movie {
+    pub(crate) year: u32,
+    pub(crate) watched: bool,
+    pub(crate) movie: String,
+    #[serde(default)]
+    pub(crate) plot: Option<String>,
+    #[serde(default)]
+    pub(crate) rating: Option<f32>,
+}
+
+/// Which rows are shown, cycled with `f`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FilterMode {
+    All,
+    Unwatched,
+    Watched,
+}
+
+impl FilterMode {
+    pub(crate) fn next(self) -> Self {
+        match self {
+            FilterMode::All => FilterMode::Unwatched,
+            FilterMode::Unwatched => FilterMode::Watched,
+            FilterMode::Watched => FilterMode::All,
+        }
+    }
+
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            FilterMode::All => "All",
+            FilterMode::Unwatched => "Unwatched",
+            FilterMode::Watched => "Watched",
+        }
+    }
+
+    fn matches(self, movie: &Movie) -> bool {
+        match self {
+            FilterMode::All => true,
+            FilterMode::Unwatched => !movie.watched,
+            FilterMode::Watched => movie.watched,
+        }
+    }
+}
+
+/// The watchlist itself: loading/saving, toggling, stats, and the
+/// search/filter predicate. Has no dependency on ratatui or any other UI
+/// toolkit, so the TUI and the egui frontend both drive it the same way.
+pub(crate) struct Backend {
+    pub(crate) movies: Vec<Movie>,
+    store: Box<dyn Store>,
+    pub(crate) filter: FilterMode,
+    pub(crate) search_query: String,
+    /// Indices into `movies` that pass the current filter and search
+    /// query, in display order. Recomputed whenever any of those change.
+    pub(crate) visible_indices: Vec<usize>,
+    /// When we last wrote to `store`, so a filesystem-change notification
+    /// arriving just after can be recognized as our own write rather than
+    /// an external edit.
+    last_saved: Option<Instant>,
+}
+
+impl Backend {
+    pub(crate) fn new(store: Box<dyn Store>) -> Self {
+        let movies = store.load().unwrap_or_else(|e| {
+            eprintln!("Error loading movies: {}", e);
+            Vec::new()
+        });
+
+        let mut backend = Self {
+            movies,
+            store,
+            filter: FilterMode::All,
+            search_query: String::new(),
+            visible_indices: Vec::new(),
+            last_saved: None,
+        };
+        backend.recompute_visible();
+        backend
+    }
+
+    pub(crate) fn save(&mut self) -> io::Result<()> {
+        self.last_saved = Some(Instant::now());
+        self.store.save(&self.movies)
+    }
+
+    /// The file backing `store`, to watch for external edits.
+    pub(crate) fn watch_path(&self) -> &str {
+        self.store.path()
+    }
+
+    /// Whether a notification of a change to `watch_path` right now would
+    /// plausibly be our own write rather than an external edit.
+    pub(crate) fn just_saved(&self) -> bool {
+        self.last_saved
+            .is_some_and(|t| t.elapsed() < OWN_WRITE_GRACE_PERIOD)
+    }
+
+    /// Re-reads `movies` from `store`, discarding in-memory state. Used
+    /// when the backing file changed outside this process.
+    pub(crate) fn reload(&mut self) {
+        match self.store.load() {
+            Ok(movies) => {
+                self.movies = movies;
+                self.recompute_visible();
+            }
+            Err(e) => eprintln!("Error reloading movies: {}", e),
+        }
+    }
+
+    pub(crate) fn recompute_visible(&mut self) {
+        let query = self.search_query.to_lowercase();
+        self.visible_indices = self
+            .movies
+            .iter()
+            .enumerate()
+            .filter(|(_, m)| self.filter.matches(m))
+            .filter(|(_, m)| query.is_empty() || m.movie.to_lowercase().contains(&query))
+            .map(|(i, _)| i)
+            .collect();
+    }
+
+    pub(crate) fn get_stats(&self) -> (usize, usize) {
+        let watched = self.movies.iter().filter(|m| m.watched).count();
+        (watched, self.movies.len())
+    }
+
+    pub(crate) fn cycle_filter(&mut self) {
+        self.filter = self.filter.next();
+        self.recompute_visible();
+    }
+
+    pub(crate) fn toggle_watched(&mut self, movie_index: usize) {
+        if let Some(movie) = self.movies.get_mut(movie_index) {
+            movie.watched = !movie.watched;
+        }
+        self.recompute_visible();
+        let _ = self.save();
+    }
+
+    /// Appends `movie` and returns its index into `movies`.
+    pub(crate) fn insert_movie(&mut self, movie: Movie) -> usize {
+        self.movies.push(movie);
+        let index = self.movies.len() - 1;
+        self.recompute_visible();
+        let _ = self.save();
+        index
+    }
+
+    pub(crate) fn add_movie(&mut self, title: String, year: u32) -> usize {
+        self.insert_movie(Movie {
+            year,
+            watched: false,
+            movie: title,
+            plot: None,
+            rating: None,
+        })
+    }
+
+    pub(crate) fn edit_movie(&mut self, movie_index: usize, title: String, year: u32) {
+        if let Some(movie) = self.movies.get_mut(movie_index) {
+            movie.movie = title;
+            movie.year = year;
+        }
+        self.recompute_visible();
+        let _ = self.save();
+    }
+
+    pub(crate) fn delete_movie(&mut self, movie_index: usize) {
+        if movie_index < self.movies.len() {
+            self.movies.remove(movie_index);
+            self.recompute_visible();
+            let _ = self.save();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A `Store` that never touches disk, so tests can exercise `Backend`
+    /// without a filesystem.
+    struct NullStore;
+
+    impl Store for NullStore {
+        fn load(&self) -> io::Result<Vec<Movie>> {
+            Ok(Vec::new())
+        }
+
+        fn save(&self, _movies: &[Movie]) -> io::Result<()> {
+            Ok(())
+        }
+
+        fn path(&self) -> &str {
+            "test"
+        }
+    }
+
+    fn movie(title: &str, year: u32, watched: bool) -> Movie {
+        Movie {
+            year,
+            watched,
+            movie: title.to_string(),
+            plot: None,
+            rating: None,
+        }
+    }
+
+    fn backend_with(movies: Vec<Movie>) -> Backend {
+        let mut backend = Backend::new(Box::new(NullStore));
+        backend.movies = movies;
+        backend.recompute_visible();
+        backend
+    }
+
+    #[test]
+    fn filter_restricts_to_watched_state() {
+        let mut backend = backend_with(vec![
+            movie("Seen", 2000, true),
+            movie("Unseen", 2001, false),
+        ]);
+
+        backend.filter = FilterMode::Watched;
+        backend.recompute_visible();
+        assert_eq!(backend.visible_indices, vec![0]);
+
+        backend.filter = FilterMode::Unwatched;
+        backend.recompute_visible();
+        assert_eq!(backend.visible_indices, vec![1]);
+
+        backend.filter = FilterMode::All;
+        backend.recompute_visible();
+        assert_eq!(backend.visible_indices, vec![0, 1]);
+    }
+
+    #[test]
+    fn search_query_matches_case_insensitively() {
+        let mut backend = backend_with(vec![
+            movie("The Matrix", 1999, false),
+            movie("Inception", 2010, false),
+        ]);
+
+        backend.search_query = "matrix".to_string();
+        backend.recompute_visible();
+        assert_eq!(backend.visible_indices, vec![0]);
+    }
+
+    #[test]
+    fn filter_and_search_combine() {
+        let mut backend = backend_with(vec![
+            movie("The Matrix", 1999, true),
+            movie("The Matrix Reloaded", 2003, false),
+        ]);
+
+        backend.filter = FilterMode::Unwatched;
+        backend.search_query = "matrix".to_string();
+        backend.recompute_visible();
+        assert_eq!(backend.visible_indices, vec![1]);
+    }
+
+    #[test]
+    fn toggle_watched_updates_visibility_under_filter() {
+        let mut backend = backend_with(vec![movie("Arrival", 2016, false)]);
+        backend.filter = FilterMode::Unwatched;
+        backend.recompute_visible();
+        assert_eq!(backend.visible_indices, vec![0]);
+
+        backend.toggle_watched(0);
+        assert!(backend.movies[0].watched);
+        assert!(backend.visible_indices.is_empty());
+    }
+}
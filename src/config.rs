@@ -0,0 +1,20 @@
+use serde::Deserialize;
+use std::fs;
+
+/// Settings for the optional online metadata lookup, read from
+/// `config.toml` in the current working directory.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MetadataConfig {
+    pub base_url: String,
+    pub api_key: String,
+}
+
+impl MetadataConfig {
+    /// Loads the config, returning `None` if the file is missing or
+    /// malformed. A missing config simply disables metadata lookup rather
+    /// than being treated as an error.
+    pub fn load() -> Option<Self> {
+        let contents = fs::read_to_string("config.toml").ok()?;
+        toml::from_str(&contents).ok()
+    }
+}
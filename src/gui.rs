@@ -0,0 +1,83 @@
+use crate::backend::Backend;
+use crate::store::Store;
+use eframe::egui;
+
+/// Desktop frontend built on egui, sharing `Backend` with the TUI so both
+/// surfaces stay in sync with the same storage and mutation logic.
+struct GuiApp {
+    backend: Backend,
+    new_title: String,
+    new_year: String,
+}
+
+impl GuiApp {
+    fn new(store: Box<dyn Store>) -> Self {
+        Self {
+            backend: Backend::new(store),
+            new_title: String::new(),
+            new_year: String::new(),
+        }
+    }
+}
+
+impl eframe::App for GuiApp {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let (watched, total) = self.backend.get_stats();
+            ui.heading(format!("Movie Watchlist ({}/{} watched)", watched, total));
+
+            ui.horizontal(|ui| {
+                if ui
+                    .button(format!("Filter: {}", self.backend.filter.label()))
+                    .clicked()
+                {
+                    self.backend.cycle_filter();
+                }
+                if ui
+                    .text_edit_singleline(&mut self.backend.search_query)
+                    .changed()
+                {
+                    self.backend.recompute_visible();
+                }
+            });
+
+            ui.separator();
+
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                for &idx in &self.backend.visible_indices.clone() {
+                    let movie = &self.backend.movies[idx];
+                    let mut watched = movie.watched;
+                    let label = format!("{} - {}", movie.year, movie.movie);
+                    if ui.checkbox(&mut watched, label).changed() {
+                        self.backend.toggle_watched(idx);
+                    }
+                }
+            });
+
+            ui.separator();
+
+            ui.horizontal(|ui| {
+                ui.text_edit_singleline(&mut self.new_title);
+                ui.text_edit_singleline(&mut self.new_year);
+                if ui.button("Add").clicked() {
+                    if let Ok(year) = self.new_year.parse() {
+                        self.backend.add_movie(self.new_title.clone(), year);
+                        self.new_title.clear();
+                        self.new_year.clear();
+                    }
+                }
+            });
+        });
+    }
+}
+
+/// Runs the egui desktop frontend against `store`, blocking until the
+/// window is closed.
+pub fn run(store: Box<dyn Store>) {
+    let options = eframe::NativeOptions::default();
+    let _ = eframe::run_native(
+        "Movie Watchlist",
+        options,
+        Box::new(|_cc| Ok(Box::new(GuiApp::new(store)))),
+    );
+}
@@ -0,0 +1,110 @@
+/// A reusable single-line text input with cursor support, used by the
+/// add/edit prompts in the main event loop.
+#[derive(Clone, Default)]
+pub struct TextInput {
+    chars: Vec<char>,
+    cursor: usize,
+}
+
+impl TextInput {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts the input pre-filled with `value`, cursor placed at the end.
+    pub fn with_value(value: &str) -> Self {
+        let chars: Vec<char> = value.chars().collect();
+        let cursor = chars.len();
+        Self { chars, cursor }
+    }
+
+    pub fn value(&self) -> String {
+        self.chars.iter().collect()
+    }
+
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    pub fn insert(&mut self, c: char) {
+        self.chars.insert(self.cursor, c);
+        self.cursor += 1;
+    }
+
+    pub fn backspace(&mut self) {
+        if self.cursor > 0 {
+            self.cursor -= 1;
+            self.chars.remove(self.cursor);
+        }
+    }
+
+    pub fn move_left(&mut self) {
+        if self.cursor > 0 {
+            self.cursor -= 1;
+        }
+    }
+
+    pub fn move_right(&mut self) {
+        if self.cursor < self.chars.len() {
+            self.cursor += 1;
+        }
+    }
+
+    pub fn home(&mut self) {
+        self.cursor = 0;
+    }
+
+    pub fn end(&mut self) {
+        self.cursor = self.chars.len();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_and_backspace_track_cursor() {
+        let mut input = TextInput::new();
+        input.insert('a');
+        input.insert('b');
+        input.insert('c');
+        assert_eq!(input.value(), "abc");
+        assert_eq!(input.cursor(), 3);
+
+        input.backspace();
+        assert_eq!(input.value(), "ab");
+        assert_eq!(input.cursor(), 2);
+    }
+
+    #[test]
+    fn move_left_right_are_clamped() {
+        let mut input = TextInput::with_value("hi");
+        assert_eq!(input.cursor(), 2);
+
+        input.move_right();
+        assert_eq!(input.cursor(), 2);
+
+        input.move_left();
+        input.move_left();
+        input.move_left();
+        assert_eq!(input.cursor(), 0);
+    }
+
+    #[test]
+    fn home_and_end_jump_to_bounds() {
+        let mut input = TextInput::with_value("movie");
+        input.home();
+        assert_eq!(input.cursor(), 0);
+        input.end();
+        assert_eq!(input.cursor(), 5);
+    }
+
+    #[test]
+    fn insert_happens_at_cursor_not_end() {
+        let mut input = TextInput::with_value("ac");
+        input.move_left();
+        input.insert('b');
+        assert_eq!(input.value(), "abc");
+    }
+}
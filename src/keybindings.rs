@@ -0,0 +1,185 @@
+use crossterm::event::KeyCode;
+use std::collections::HashMap;
+use std::fs;
+
+/// A cursor movement, parameterized by how many rows to move where that's
+/// meaningful. Keybindings always carry `Up(1)`/`Down(1)`; a typed numeric
+/// prefix (e.g. `5j`) is passed separately to `App::apply_action`, which
+/// repeats the step that many times.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Movement {
+    Up(usize),
+    Down(usize),
+    PageUp,
+    PageDown,
+    Top,
+    Bottom,
+}
+
+/// Everything a keypress in `AppMode::Normal` can resolve to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    Move(Movement),
+    ToggleWatched,
+    Add,
+    Edit,
+    Delete,
+    Search,
+    CycleFilter,
+    ToggleSelection,
+    InvertSelection,
+    ClearSelection,
+    MarkWatched,
+    MarkUnwatched,
+    Quit,
+}
+
+impl Action {
+    fn from_name(name: &str) -> Option<Self> {
+        use Action::*;
+        use Movement::*;
+        Some(match name {
+            "up" => Move(Up(1)),
+            "down" => Move(Down(1)),
+            "page_up" => Move(PageUp),
+            "page_down" => Move(PageDown),
+            "top" => Move(Top),
+            "bottom" => Move(Bottom),
+            "toggle_watched" => ToggleWatched,
+            "add" => Add,
+            "edit" => Edit,
+            "delete" => Delete,
+            "search" => Search,
+            "cycle_filter" => CycleFilter,
+            "toggle_selection" => ToggleSelection,
+            "invert_selection" => InvertSelection,
+            "clear_selection" => ClearSelection,
+            "mark_watched" => MarkWatched,
+            "mark_unwatched" => MarkUnwatched,
+            "quit" => Quit,
+            _ => return None,
+        })
+    }
+}
+
+/// Maps a key name (e.g. `"j"`, `" "` for space, `"up"`) to an `Action`. Built-in
+/// defaults mirror vim, and can be remapped via an optional
+/// `keybindings.toml` of the form `j = "down"`.
+pub struct Keybindings {
+    map: HashMap<String, Action>,
+}
+
+impl Keybindings {
+    fn defaults() -> HashMap<String, Action> {
+        use Action::*;
+        use Movement::*;
+        let mut map = HashMap::new();
+        map.insert("j".to_string(), Move(Down(1)));
+        map.insert("down".to_string(), Move(Down(1)));
+        map.insert("n".to_string(), Move(Down(1)));
+        map.insert("k".to_string(), Move(Up(1)));
+        map.insert("up".to_string(), Move(Up(1)));
+        map.insert("N".to_string(), Move(Up(1)));
+        map.insert("pagedown".to_string(), Move(PageDown));
+        map.insert("pageup".to_string(), Move(PageUp));
+        map.insert("g".to_string(), Move(Top));
+        map.insert("G".to_string(), Move(Bottom));
+        map.insert("t".to_string(), ToggleWatched);
+        map.insert("a".to_string(), Add);
+        map.insert("e".to_string(), Edit);
+        map.insert("d".to_string(), Delete);
+        map.insert("/".to_string(), Search);
+        map.insert("f".to_string(), CycleFilter);
+        map.insert(" ".to_string(), ToggleSelection);
+        map.insert("v".to_string(), ToggleSelection);
+        map.insert("i".to_string(), InvertSelection);
+        map.insert("c".to_string(), ClearSelection);
+        map.insert("W".to_string(), MarkWatched);
+        map.insert("U".to_string(), MarkUnwatched);
+        map.insert("q".to_string(), Quit);
+        map
+    }
+
+    /// Loads user overrides from `keybindings.toml`, falling back to the
+    /// built-in defaults for any key not remapped (and if the file is
+    /// missing or malformed).
+    pub fn load() -> Self {
+        let mut map = Self::defaults();
+
+        if let Ok(contents) = fs::read_to_string("keybindings.toml") {
+            if let Ok(overrides) = toml::from_str::<HashMap<String, String>>(&contents) {
+                for (key, action_name) in overrides {
+                    if let Some(action) = Action::from_name(&action_name) {
+                        map.insert(key, action);
+                    }
+                }
+            }
+        }
+
+        Self { map }
+    }
+
+    pub fn resolve(&self, key: &str) -> Option<Action> {
+        self.map.get(key).copied()
+    }
+}
+
+/// Converts a crossterm key code into the string form used by
+/// `Keybindings`, e.g. `KeyCode::Up` -> `"up"`, `KeyCode::Char('j')` ->
+/// `"j"`. Returns `None` for keys that can't be bound to an action.
+pub fn key_name(code: KeyCode) -> Option<String> {
+    Some(match code {
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::Up => "up".to_string(),
+        KeyCode::Down => "down".to_string(),
+        KeyCode::PageUp => "pageup".to_string(),
+        KeyCode::PageDown => "pagedown".to_string(),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn defaults() -> Keybindings {
+        Keybindings {
+            map: Keybindings::defaults(),
+        }
+    }
+
+    #[test]
+    fn vim_movement_keys_resolve_to_the_same_action_as_arrows() {
+        let keybindings = defaults();
+        assert_eq!(keybindings.resolve("j"), keybindings.resolve("down"));
+        assert_eq!(keybindings.resolve("k"), keybindings.resolve("up"));
+        assert_eq!(keybindings.resolve("j"), Some(Action::Move(Movement::Down(1))));
+    }
+
+    #[test]
+    fn unbound_key_resolves_to_none() {
+        let keybindings = defaults();
+        assert_eq!(keybindings.resolve("z"), None);
+    }
+
+    #[test]
+    fn from_name_accepts_every_toml_action_name() {
+        let names = [
+            "up", "down", "page_up", "page_down", "top", "bottom", "toggle_watched", "add",
+            "edit", "delete", "search", "cycle_filter", "toggle_selection", "invert_selection",
+            "clear_selection", "mark_watched", "mark_unwatched", "quit",
+        ];
+        for name in names {
+            assert!(Action::from_name(name).is_some(), "{name} should resolve to an action");
+        }
+        assert_eq!(Action::from_name("not_a_real_action"), None);
+    }
+
+    #[test]
+    fn key_name_maps_known_keys_and_rejects_unbindable_ones() {
+        assert_eq!(key_name(KeyCode::Char('j')), Some("j".to_string()));
+        assert_eq!(key_name(KeyCode::Up), Some("up".to_string()));
+        assert_eq!(key_name(KeyCode::PageDown), Some("pagedown".to_string()));
+        assert_eq!(key_name(KeyCode::F(1)), None);
+    }
+}
@@ -1,8 +1,22 @@
+mod backend;
+mod config;
+#[cfg(feature = "gui")]
+mod gui;
+mod input;
+mod keybindings;
+mod metadata;
+mod store;
+
+use backend::{Backend, Movie};
+use config::MetadataConfig;
 use crossterm::{
     event::{self, Event, KeyCode, KeyEventKind},
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
     ExecutableCommand,
 };
+use input::TextInput;
+use keybindings::{key_name, Action, Keybindings, Movement};
+use metadata::MetadataResult;
 use ratatui::{
     backend::CrosstermBackend,
     layout::{Constraint, Layout},
@@ -11,84 +25,146 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
     Terminal,
 };
-use serde::{Deserialize, Serialize};
-use std::fs;
+use std::collections::HashSet;
 use std::io::{self, stdout};
-use std::path::Path;
+use std::sync::mpsc;
+use std::thread;
+use store::{CsvStore, JsonFileStore, Store};
 
-#[derive(Clone, Serialize, Deserialize)]
-struct Movie {
-    year: u32,
-    watched: bool,
-    movie: String,
+/// What the main event loop should do with the next keypress.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum AppMode {
+    Normal,
+    AddingTitle,
+    AddingYear,
+    Editing,
+    /// Waiting on the background metadata lookup triggered by `AddingTitle`.
+    SearchingMetadata,
+    /// Picking one of several metadata search results.
+    SelectingMetadata,
+    /// Typing an incremental search query, triggered by `/`.
+    Searching,
 }
 
 struct App {
-    movies: Vec<Movie>,
+    backend: Backend,
+    /// Position within `backend.visible_indices`, not an index into
+    /// `backend.movies`.
     selected: usize,
-    save_path: String,
     list_state: ListState,
+    mode: AppMode,
+    input: TextInput,
+    pending_title: String,
+    /// Index of the movie being edited, or `None` when adding a new one.
+    editing_index: Option<usize>,
+    /// Set when `confirm_year` rejects the typed value, shown in the
+    /// footer until the user edits the field or leaves `AddingYear`.
+    year_error: bool,
+    metadata_config: Option<MetadataConfig>,
+    metadata_rx: Option<mpsc::Receiver<Result<Vec<MetadataResult>, String>>>,
+    metadata_results: Vec<MetadataResult>,
+    metadata_list_state: ListState,
+    /// Indices into `backend.movies` marked for a batch action.
+    selected_set: HashSet<usize>,
+    keybindings: Keybindings,
+    /// Height of the movie list viewport, used for `PageUp`/`PageDown`.
+    /// Updated each frame from the layout.
+    list_height: usize,
+    /// Notified by the filesystem watcher spawned in `main` whenever the
+    /// backing file changes on disk. `None` if the watcher couldn't be
+    /// started.
+    fs_rx: Option<mpsc::Receiver<()>>,
 }
 
 impl App {
-    fn new(save_path: &str) -> Self {
-        let movies = Self::load_from_file(save_path);
-        let mut list_state = ListState::default();
-        list_state.select(Some(0));
-        
-        Self {
-            movies,
+    fn new(store: Box<dyn Store>) -> Self {
+        let mut app = Self {
+            backend: Backend::new(store),
             selected: 0,
-            save_path: save_path.to_string(),
-            list_state,
+            list_state: ListState::default(),
+            mode: AppMode::Normal,
+            input: TextInput::new(),
+            pending_title: String::new(),
+            editing_index: None,
+            year_error: false,
+            metadata_config: MetadataConfig::load(),
+            metadata_rx: None,
+            metadata_results: Vec::new(),
+            metadata_list_state: ListState::default(),
+            selected_set: HashSet::new(),
+            keybindings: Keybindings::load(),
+            list_height: 10,
+            fs_rx: None,
+        };
+        app.sync_selection();
+        app
+    }
+
+    /// Drains pending filesystem-change notifications and, if the backing
+    /// file was edited externally (not by our own `Backend::save`),
+    /// reloads it and tries to keep the same movie selected. The old
+    /// `selected_set` indexes movies that may no longer exist or may have
+    /// shifted, so a reload always drops it rather than risk a later batch
+    /// action silently hitting the wrong rows.
+    fn poll_fs_changes(&mut self) {
+        let Some(rx) = &self.fs_rx else {
+            return;
+        };
+
+        let mut changed = false;
+        while rx.try_recv().is_ok() {
+            changed = true;
+        }
+
+        if changed && !self.backend.just_saved() {
+            let previous = self.current_movie_index();
+            self.backend.reload();
+            self.selected_set.clear();
+            match previous {
+                Some(i) => self.select_movie_index(i),
+                None => self.sync_selection(),
+            }
         }
     }
 
-    fn load_from_file(path: &str) -> Vec<Movie> {
-        if Path::new(path).exists() {
-            match fs::read_to_string(path) {
-                Ok(contents) => {
-                    match serde_json::from_str::<Vec<Movie>>(&contents) {
-                        Ok(movies) => return movies,
-                        Err(e) => {
-                            eprintln!("Error parsing JSON: {}", e);
-                        }
-                    }
-                }
-                Err(e) => {
-                    eprintln!("Error reading file: {}", e);
-                }
+    /// Clamps `selected`/`list_state` to `backend.visible_indices` after a
+    /// mutation that may have changed which rows are visible.
+    fn sync_selection(&mut self) {
+        if self.backend.visible_indices.is_empty() {
+            self.selected = 0;
+            self.list_state.select(None);
+        } else {
+            if self.selected >= self.backend.visible_indices.len() {
+                self.selected = self.backend.visible_indices.len() - 1;
             }
+            self.list_state.select(Some(self.selected));
         }
-        
-        // Default empty list if file doesn't exist or can't be loaded
-        vec![]
     }
 
-    fn save_to_file(&self) -> io::Result<()> {
-        let json = serde_json::to_string_pretty(&self.movies)?;
-        fs::write(&self.save_path, json)?;
-        Ok(())
+    /// The index into `backend.movies` of the currently selected row, if
+    /// any.
+    fn current_movie_index(&self) -> Option<usize> {
+        self.backend.visible_indices.get(self.selected).copied()
     }
 
     fn toggle_current(&mut self) {
-        if !self.movies.is_empty() {
-            self.movies[self.selected].watched = !self.movies[self.selected].watched;
-            let _ = self.save_to_file(); // Auto-save on change
+        if let Some(i) = self.current_movie_index() {
+            self.backend.toggle_watched(i);
+            self.sync_selection();
         }
     }
 
     fn next(&mut self) {
-        if !self.movies.is_empty() {
-            self.selected = (self.selected + 1) % self.movies.len();
+        if !self.backend.visible_indices.is_empty() {
+            self.selected = (self.selected + 1) % self.backend.visible_indices.len();
             self.list_state.select(Some(self.selected));
         }
     }
 
     fn previous(&mut self) {
-        if !self.movies.is_empty() {
+        if !self.backend.visible_indices.is_empty() {
             self.selected = if self.selected == 0 {
-                self.movies.len() - 1
+                self.backend.visible_indices.len() - 1
             } else {
                 self.selected - 1
             };
@@ -96,22 +172,396 @@ impl App {
         }
     }
 
-    fn get_stats(&self) -> (usize, usize) {
-        let watched = self.movies.iter().filter(|m| m.watched).count();
-        let total = self.movies.len();
-        (watched, total)
+    fn page_up(&mut self) {
+        if !self.backend.visible_indices.is_empty() {
+            self.selected = self.selected.saturating_sub(self.list_height.max(1));
+            self.list_state.select(Some(self.selected));
+        }
+    }
+
+    fn page_down(&mut self) {
+        if !self.backend.visible_indices.is_empty() {
+            self.selected = (self.selected + self.list_height.max(1))
+                .min(self.backend.visible_indices.len() - 1);
+            self.list_state.select(Some(self.selected));
+        }
+    }
+
+    fn go_top(&mut self) {
+        if !self.backend.visible_indices.is_empty() {
+            self.selected = 0;
+            self.list_state.select(Some(0));
+        }
+    }
+
+    fn go_bottom(&mut self) {
+        if !self.backend.visible_indices.is_empty() {
+            self.selected = self.backend.visible_indices.len() - 1;
+            self.list_state.select(Some(self.selected));
+        }
+    }
+
+    /// Applies a resolved keybinding, repeating `Up`/`Down` movements
+    /// `count` times. Every other action, including page/top/bottom
+    /// movements, runs once regardless of a typed count prefix.
+    fn apply_action(&mut self, action: Action, count: usize) {
+        match action {
+            Action::Move(Movement::Up(_)) => {
+                for _ in 0..count {
+                    self.previous();
+                }
+            }
+            Action::Move(Movement::Down(_)) => {
+                for _ in 0..count {
+                    self.next();
+                }
+            }
+            Action::Move(Movement::PageUp) => self.page_up(),
+            Action::Move(Movement::PageDown) => self.page_down(),
+            Action::Move(Movement::Top) => self.go_top(),
+            Action::Move(Movement::Bottom) => self.go_bottom(),
+            Action::ToggleWatched => self.toggle_current(),
+            Action::Add => self.start_add(),
+            Action::Edit => self.start_edit(),
+            Action::Delete => self.delete_current(),
+            Action::Search => self.start_search(),
+            Action::CycleFilter => self.cycle_filter(),
+            Action::ToggleSelection => self.toggle_selection(),
+            Action::InvertSelection => self.invert_selection(),
+            Action::ClearSelection => self.clear_selection(),
+            Action::MarkWatched => self.mark_selected_watched(true),
+            Action::MarkUnwatched => self.mark_selected_watched(false),
+            Action::Quit => {}
+        }
+    }
+
+    fn cycle_filter(&mut self) {
+        self.backend.cycle_filter();
+        self.sync_selection();
+    }
+
+    /// Enters incremental search mode, resuming the previous query if any.
+    fn start_search(&mut self) {
+        self.input = TextInput::with_value(&self.backend.search_query);
+        self.mode = AppMode::Searching;
+    }
+
+    fn update_search_query(&mut self) {
+        self.backend.search_query = self.input.value();
+        self.backend.recompute_visible();
+        self.sync_selection();
+    }
+
+    fn cancel_search(&mut self) {
+        self.backend.search_query.clear();
+        self.input = TextInput::new();
+        self.backend.recompute_visible();
+        self.sync_selection();
+        self.mode = AppMode::Normal;
+    }
+
+    /// Enters add mode, prompting for a title first.
+    fn start_add(&mut self) {
+        self.editing_index = None;
+        self.pending_title.clear();
+        self.input = TextInput::new();
+        self.mode = AppMode::AddingTitle;
+    }
+
+    /// Enters edit mode for the selected row, pre-filling its title.
+    fn start_edit(&mut self) {
+        if let Some(i) = self.current_movie_index() {
+            let movie = &self.backend.movies[i];
+            self.editing_index = Some(i);
+            self.pending_title.clear();
+            self.input = TextInput::with_value(&movie.movie);
+            self.mode = AppMode::Editing;
+        }
+    }
+
+    /// Confirms the title field. When adding a new movie (not editing) and a
+    /// metadata provider is configured, this kicks off a background search
+    /// instead of going straight to the year prompt.
+    fn confirm_title(&mut self) {
+        self.pending_title = self.input.value();
+
+        if self.editing_index.is_none() {
+            if let Some(config) = self.metadata_config.clone() {
+                self.start_metadata_search(config);
+                return;
+            }
+        }
+
+        self.begin_year_entry();
+    }
+
+    fn begin_year_entry(&mut self) {
+        let year = self
+            .editing_index
+            .and_then(|i| self.backend.movies.get(i))
+            .map(|m| m.year.to_string())
+            .unwrap_or_default();
+        self.input = TextInput::with_value(&year);
+        self.year_error = false;
+        self.mode = AppMode::AddingYear;
+    }
+
+    fn start_metadata_search(&mut self, config: MetadataConfig) {
+        let (tx, rx) = mpsc::channel();
+        let title = self.pending_title.clone();
+        thread::spawn(move || {
+            let _ = tx.send(metadata::search(&config, &title));
+        });
+        self.metadata_rx = Some(rx);
+        self.mode = AppMode::SearchingMetadata;
+    }
+
+    /// Checks whether a background metadata search has finished, called on
+    /// every loop iteration while `mode == SearchingMetadata`. Falls back to
+    /// manual year entry on failure or an empty result set.
+    fn poll_metadata_search(&mut self) {
+        let Some(rx) = &self.metadata_rx else {
+            return;
+        };
+
+        match rx.try_recv() {
+            Ok(Ok(results)) if !results.is_empty() => {
+                self.metadata_rx = None;
+                self.metadata_results = results;
+                self.metadata_list_state.select(Some(0));
+                self.mode = AppMode::SelectingMetadata;
+            }
+            Ok(Ok(_)) | Ok(Err(_)) => {
+                self.metadata_rx = None;
+                self.begin_year_entry();
+            }
+            Err(mpsc::TryRecvError::Empty) => {}
+            Err(mpsc::TryRecvError::Disconnected) => {
+                self.metadata_rx = None;
+                self.begin_year_entry();
+            }
+        }
+    }
+
+    fn metadata_next(&mut self) {
+        if !self.metadata_results.is_empty() {
+            let i = match self.metadata_list_state.selected() {
+                Some(i) => (i + 1) % self.metadata_results.len(),
+                None => 0,
+            };
+            self.metadata_list_state.select(Some(i));
+        }
+    }
+
+    fn metadata_previous(&mut self) {
+        if !self.metadata_results.is_empty() {
+            let i = match self.metadata_list_state.selected() {
+                Some(0) | None => self.metadata_results.len() - 1,
+                Some(i) => i - 1,
+            };
+            self.metadata_list_state.select(Some(i));
+        }
+    }
+
+    /// Inserts the selected metadata candidate as a new movie.
+    fn confirm_metadata_selection(&mut self) {
+        if let Some(i) = self.metadata_list_state.selected() {
+            if let Some(result) = self.metadata_results.get(i).cloned() {
+                let idx = self.backend.insert_movie(Movie {
+                    year: result.year,
+                    watched: false,
+                    movie: result.title,
+                    plot: result.plot,
+                    rating: result.rating,
+                });
+                self.select_movie_index(idx);
+            }
+        }
+        self.metadata_results.clear();
+        self.mode = AppMode::Normal;
+    }
+
+    /// Moves selection to `movie_index` if it's currently visible,
+    /// otherwise just re-clamps. Used after inserting a new movie.
+    fn select_movie_index(&mut self, movie_index: usize) {
+        if let Some(pos) = self
+            .backend
+            .visible_indices
+            .iter()
+            .position(|&i| i == movie_index)
+        {
+            self.selected = pos;
+            self.list_state.select(Some(pos));
+        } else {
+            self.sync_selection();
+        }
+    }
+
+    /// Confirms the year field, finishing the add/edit flow. If the year
+    /// doesn't parse as a `u32`, stays in `AddingYear` and sets
+    /// `year_error` so the footer can tell the user why.
+    fn confirm_year(&mut self) {
+        let year: u32 = match self.input.value().parse() {
+            Ok(year) => year,
+            Err(_) => {
+                self.year_error = true;
+                return;
+            }
+        };
+
+        match self.editing_index.take() {
+            Some(i) => {
+                self.backend.edit_movie(i, self.pending_title.clone(), year);
+                self.sync_selection();
+            }
+            None => {
+                let idx = self.backend.add_movie(self.pending_title.clone(), year);
+                self.select_movie_index(idx);
+            }
+        }
+
+        self.mode = AppMode::Normal;
+    }
+
+    fn cancel_input(&mut self) {
+        self.editing_index = None;
+        self.input = TextInput::new();
+        self.year_error = false;
+        self.metadata_rx = None;
+        self.metadata_results.clear();
+        self.mode = AppMode::Normal;
+    }
+
+    /// Deletes the selected row, fixing up `selected`/`list_state`.
+    fn delete_current(&mut self) {
+        if let Some(i) = self.current_movie_index() {
+            self.backend.delete_movie(i);
+            self.selected_set = self
+                .selected_set
+                .iter()
+                .filter(|&&j| j != i)
+                .map(|&j| if j > i { j - 1 } else { j })
+                .collect();
+            self.sync_selection();
+        }
+    }
+
+    /// Marks the current row selected (or unselects it if already marked).
+    fn toggle_selection(&mut self) {
+        if let Some(i) = self.current_movie_index() {
+            if !self.selected_set.remove(&i) {
+                self.selected_set.insert(i);
+            }
+        }
+    }
+
+    /// Toggles selection on every row currently visible under the active
+    /// filter/search. Rows hidden by the filter are left untouched, so `i`
+    /// can't silently select movies the user can't see.
+    fn invert_selection(&mut self) {
+        for i in self.backend.visible_indices.clone() {
+            if !self.selected_set.remove(&i) {
+                self.selected_set.insert(i);
+            }
+        }
+    }
+
+    fn clear_selection(&mut self) {
+        self.selected_set.clear();
+    }
+
+    /// Sets `watched` on every selected movie and saves.
+    fn mark_selected_watched(&mut self, watched: bool) {
+        if self.selected_set.is_empty() {
+            return;
+        }
+        for &i in &self.selected_set {
+            if let Some(movie) = self.backend.movies.get_mut(i) {
+                movie.watched = watched;
+            }
+        }
+        self.backend.recompute_visible();
+        let _ = self.backend.save();
+        self.sync_selection();
     }
 }
 
+/// Picks a `Store` from CLI flags: `--path <file>` (default `movies.json`,
+/// or `movies.csv` with `--csv`) and `--csv` to use the CSV backend instead
+/// of JSON. Also reports
+/// whether `--gui` was passed, to launch the egui frontend instead of the
+/// TUI.
+fn args_and_store() -> (Box<dyn Store>, bool) {
+    let args: Vec<String> = std::env::args().collect();
+    let mut path: Option<String> = None;
+    let mut use_csv = false;
+    let mut use_gui = false;
+
+    let mut iter = args.into_iter().skip(1);
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--csv" => use_csv = true,
+            "--gui" => use_gui = true,
+            "--path" => path = iter.next(),
+            _ => {}
+        }
+    }
+
+    let path = path.unwrap_or_else(|| if use_csv { "movies.csv" } else { "movies.json" }.to_string());
+
+    let store: Box<dyn Store> = if use_csv {
+        Box::new(CsvStore::new(path))
+    } else {
+        Box::new(JsonFileStore::new(path))
+    };
+    (store, use_gui)
+}
+
 fn main() -> io::Result<()> {
+    let (store, use_gui) = args_and_store();
+
+    if use_gui {
+        #[cfg(feature = "gui")]
+        {
+            gui::run(store);
+            return Ok(());
+        }
+        #[cfg(not(feature = "gui"))]
+        {
+            eprintln!("This build was compiled without the `gui` feature; rebuild with `--features gui` to use --gui.");
+            return Ok(());
+        }
+    }
+
     // Setup terminal
     enable_raw_mode()?;
     stdout().execute(EnterAlternateScreen)?;
     let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
 
-    let save_path = "movies.json";
-    let mut app = App::new(save_path);
+    let mut app = App::new(store);
     let mut should_quit = false;
+    // Numeric prefix typed before a movement key, e.g. the `5` in `5j`.
+    let mut pending_count: usize = 0;
+
+    // Watch the backing file for external edits so they show up without a
+    // restart. Kept alive for the rest of `main` by holding onto `_watcher`;
+    // dropping it would stop the notifications.
+    let (fs_tx, fs_rx) = mpsc::channel();
+    let watch_path = app.backend.watch_path().to_string();
+    let _watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+        if res.is_ok() {
+            let _ = fs_tx.send(());
+        }
+    })
+    .and_then(|mut watcher| {
+        notify::Watcher::watch(&mut watcher, std::path::Path::new(&watch_path), notify::RecursiveMode::NonRecursive)?;
+        Ok(watcher)
+    })
+    .map_err(|e| eprintln!("Could not watch {} for external changes: {}", watch_path, e))
+    .ok();
+    if _watcher.is_some() {
+        app.fs_rx = Some(fs_rx);
+    }
 
     while !should_quit {
         // Draw UI
@@ -125,11 +575,21 @@ fn main() -> io::Result<()> {
                 ])
                 .split(frame.area());
 
+            app.list_height = chunks[1].height.saturating_sub(2).max(1) as usize;
+
             // Title with stats
-            let (watched, total) = app.get_stats();
+            let (watched, total) = app.backend.get_stats();
+            let query_suffix = if app.backend.search_query.is_empty() {
+                String::new()
+            } else {
+                format!(" /{}", app.backend.search_query)
+            };
             let title = Paragraph::new(format!(
-                "Movie Watchlist ({}/{} watched)",
-                watched, total
+                "Movie Watchlist ({}/{} watched) [{}]{}",
+                watched,
+                total,
+                app.backend.filter.label(),
+                query_suffix
             ))
             .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
             .block(Block::default().borders(Borders::ALL));
@@ -137,19 +597,25 @@ fn main() -> io::Result<()> {
 
             // Movie list
             let items: Vec<ListItem> = app
-                .movies
+                .backend
+                .visible_indices
                 .iter()
-                .map(|movie| {
+                .map(|&idx| (idx, &app.backend.movies[idx]))
+                .map(|(idx, movie)| {
                     let checkbox = if movie.watched { "[✓]" } else { "[ ]" };
-                    let content = format!("{} {} - {}", checkbox, movie.year, movie.movie);
-                    
-                    let style = if movie.watched {
+                    let marker = if app.selected_set.contains(&idx) { "*" } else { " " };
+                    let content = format!("{}{} {} - {}", marker, checkbox, movie.year, movie.movie);
+
+                    let mut style = if movie.watched {
                         Style::default()
                             .fg(Color::Green)
                             .add_modifier(Modifier::DIM)
                     } else {
                         Style::default().fg(Color::White)
                     };
+                    if app.selected_set.contains(&idx) {
+                        style = style.add_modifier(Modifier::REVERSED);
+                    }
 
                     ListItem::new(Line::from(Span::styled(content, style)))
                 })
@@ -163,26 +629,185 @@ fn main() -> io::Result<()> {
                         .add_modifier(Modifier::BOLD)
                 )
                 .highlight_symbol("► ");
-            
-            frame.render_stateful_widget(list, chunks[1], &mut app.list_state);
-
-            // Help text
-            let help = Paragraph::new("↑/↓: Navigate | Space: Toggle Watched | q: Quit")
-                .style(Style::default().fg(Color::Gray))
-                .block(Block::default().borders(Borders::ALL));
-            frame.render_widget(help, chunks[2]);
+
+            if app.mode == AppMode::SelectingMetadata {
+                let candidates: Vec<ListItem> = app
+                    .metadata_results
+                    .iter()
+                    .map(|r| {
+                        let rating = r
+                            .rating
+                            .map(|v| format!(" ({:.1})", v))
+                            .unwrap_or_default();
+                        ListItem::new(format!("{} - {}{}", r.year, r.title, rating))
+                    })
+                    .collect();
+                let candidates_list = List::new(candidates)
+                    .block(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .title("Select a match"),
+                    )
+                    .highlight_style(
+                        Style::default()
+                            .fg(Color::Yellow)
+                            .add_modifier(Modifier::BOLD),
+                    )
+                    .highlight_symbol("► ");
+                frame.render_stateful_widget(candidates_list, chunks[1], &mut app.metadata_list_state);
+            } else {
+                frame.render_stateful_widget(list, chunks[1], &mut app.list_state);
+            }
+
+            // Help text, or the active input prompt while adding/editing.
+            // Input prompts also report where the text cursor should be
+            // drawn, as a byte offset into their prefix plus `input.cursor()`.
+            let (footer, cursor_prefix_len) = match app.mode {
+                AppMode::Normal => (
+                    Paragraph::new(
+                        "j/k, 5j, PgUp/PgDn, g/G: Move | t: Toggle | Space/v: Select | i: Invert | c: Clear | W/U: Mark | a/e/d: Add/Edit/Delete | /: Search | f: Filter | q: Quit",
+                    )
+                    .style(Style::default().fg(Color::Gray)),
+                    None,
+                ),
+                AppMode::Searching => {
+                    let prefix = "Search: ";
+                    (
+                        Paragraph::new(format!("{}{}", prefix, app.input.value()))
+                            .style(Style::default().fg(Color::Yellow)),
+                        Some(prefix.len()),
+                    )
+                }
+                AppMode::AddingTitle | AppMode::Editing => {
+                    let prefix = "Title: ";
+                    (
+                        Paragraph::new(format!("{}{}", prefix, app.input.value()))
+                            .style(Style::default().fg(Color::Yellow)),
+                        Some(prefix.len()),
+                    )
+                }
+                AppMode::AddingYear => {
+                    let prefix = "Year: ";
+                    let text = if app.year_error {
+                        format!("{}{}  (invalid year, must be a number)", prefix, app.input.value())
+                    } else {
+                        format!("{}{}", prefix, app.input.value())
+                    };
+                    let color = if app.year_error { Color::Red } else { Color::Yellow };
+                    (
+                        Paragraph::new(text).style(Style::default().fg(color)),
+                        Some(prefix.len()),
+                    )
+                }
+                AppMode::SearchingMetadata => (
+                    Paragraph::new("Searching…").style(Style::default().fg(Color::Yellow)),
+                    None,
+                ),
+                AppMode::SelectingMetadata => (
+                    Paragraph::new("↑/↓: Choose | Enter: Select | Esc: Cancel")
+                        .style(Style::default().fg(Color::Yellow)),
+                    None,
+                ),
+            };
+            let footer = footer.block(Block::default().borders(Borders::ALL));
+            frame.render_widget(footer, chunks[2]);
+
+            // Draw the cursor inside the footer's border, just past the
+            // prefix and however far `input.cursor()` has moved.
+            if let Some(prefix_len) = cursor_prefix_len {
+                let x = chunks[2].x + 1 + (prefix_len + app.input.cursor()) as u16;
+                let y = chunks[2].y + 1;
+                frame.set_cursor_position((x, y));
+            }
         })?;
 
+        if app.mode == AppMode::SearchingMetadata {
+            app.poll_metadata_search();
+        }
+        app.poll_fs_changes();
+
         // Handle input
         if event::poll(std::time::Duration::from_millis(100))? {
             if let Event::Key(key) = event::read()? {
                 if key.kind == KeyEventKind::Press {
-                    match key.code {
-                        KeyCode::Char('q') => should_quit = true,
-                        KeyCode::Char(' ') => app.toggle_current(),
-                        KeyCode::Down | KeyCode::Char('j') => app.next(),
-                        KeyCode::Up | KeyCode::Char('k') => app.previous(),
-                        _ => {}
+                    match app.mode {
+                        AppMode::Normal => match key.code {
+                            KeyCode::Char(c) if c.is_ascii_digit() => {
+                                pending_count = pending_count
+                                    .saturating_mul(10)
+                                    .saturating_add(c.to_digit(10).unwrap() as usize);
+                            }
+                            _ => {
+                                if let Some(action) =
+                                    key_name(key.code).and_then(|name| app.keybindings.resolve(&name))
+                                {
+                                    let count = pending_count.max(1);
+                                    if action == Action::Quit {
+                                        should_quit = true;
+                                    } else {
+                                        app.apply_action(action, count);
+                                    }
+                                }
+                                pending_count = 0;
+                            }
+                        },
+                        AppMode::Searching => match key.code {
+                            KeyCode::Enter => app.mode = AppMode::Normal,
+                            KeyCode::Esc => app.cancel_search(),
+                            KeyCode::Backspace => {
+                                app.input.backspace();
+                                app.update_search_query();
+                            }
+                            KeyCode::Left => app.input.move_left(),
+                            KeyCode::Right => app.input.move_right(),
+                            KeyCode::Home => app.input.home(),
+                            KeyCode::End => app.input.end(),
+                            KeyCode::Char(c) => {
+                                app.input.insert(c);
+                                app.update_search_query();
+                            }
+                            _ => {}
+                        },
+                        AppMode::AddingTitle | AppMode::Editing => match key.code {
+                            KeyCode::Enter => app.confirm_title(),
+                            KeyCode::Esc => app.cancel_input(),
+                            KeyCode::Backspace => app.input.backspace(),
+                            KeyCode::Left => app.input.move_left(),
+                            KeyCode::Right => app.input.move_right(),
+                            KeyCode::Home => app.input.home(),
+                            KeyCode::End => app.input.end(),
+                            KeyCode::Char(c) => app.input.insert(c),
+                            _ => {}
+                        },
+                        AppMode::AddingYear => match key.code {
+                            KeyCode::Enter => app.confirm_year(),
+                            KeyCode::Esc => app.cancel_input(),
+                            KeyCode::Backspace => {
+                                app.input.backspace();
+                                app.year_error = false;
+                            }
+                            KeyCode::Left => app.input.move_left(),
+                            KeyCode::Right => app.input.move_right(),
+                            KeyCode::Home => app.input.home(),
+                            KeyCode::End => app.input.end(),
+                            KeyCode::Char(c) if c.is_ascii_digit() => {
+                                app.input.insert(c);
+                                app.year_error = false;
+                            }
+                            _ => {}
+                        },
+                        AppMode::SearchingMetadata => {
+                            if key.code == KeyCode::Esc {
+                                app.cancel_input();
+                            }
+                        }
+                        AppMode::SelectingMetadata => match key.code {
+                            KeyCode::Enter => app.confirm_metadata_selection(),
+                            KeyCode::Esc => app.cancel_input(),
+                            KeyCode::Down | KeyCode::Char('j') => app.metadata_next(),
+                            KeyCode::Up | KeyCode::Char('k') => app.metadata_previous(),
+                            _ => {}
+                        },
                     }
                 }
             }
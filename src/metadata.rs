@@ -0,0 +1,34 @@
+use crate::config::MetadataConfig;
+use serde::Deserialize;
+
+/// A single candidate returned by the metadata provider for a title search.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MetadataResult {
+    pub title: String,
+    pub year: u32,
+    #[serde(default)]
+    pub plot: Option<String>,
+    #[serde(default)]
+    pub rating: Option<f32>,
+}
+
+#[derive(Deserialize)]
+struct SearchResponse {
+    results: Vec<MetadataResult>,
+}
+
+/// Queries the configured metadata provider for candidates matching
+/// `title`. This blocks on the HTTP request, so callers should run it on a
+/// background thread to keep the UI responsive.
+pub fn search(config: &MetadataConfig, title: &str) -> Result<Vec<MetadataResult>, String> {
+    let url = format!(
+        "{}/search?q={}&api_key={}",
+        config.base_url.trim_end_matches('/'),
+        urlencoding::encode(title),
+        config.api_key
+    );
+
+    let response = ureq::get(&url).call().map_err(|e| e.to_string())?;
+    let body: SearchResponse = response.into_json().map_err(|e| e.to_string())?;
+    Ok(body.results)
+}
@@ -0,0 +1,89 @@
+use crate::backend::Movie;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Persists and retrieves the watchlist. Implementations decide the format
+/// and location (a local file, a database, a remote service, ...) so `App`
+/// never has to know which one is in use.
+pub trait Store {
+    fn load(&self) -> io::Result<Vec<Movie>>;
+    fn save(&self, movies: &[Movie]) -> io::Result<()>;
+    /// The file backing this store. Used to watch for external
+    /// edits so the UI can live-reload them.
+    fn path(&self) -> &str;
+}
+
+/// Reproduces the original behavior: the whole watchlist as one JSON file.
+pub struct JsonFileStore {
+    path: String,
+}
+
+impl JsonFileStore {
+    pub fn new(path: impl Into<String>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl Store for JsonFileStore {
+    fn load(&self) -> io::Result<Vec<Movie>> {
+        if !Path::new(&self.path).exists() {
+            return Ok(Vec::new());
+        }
+        let contents = fs::read_to_string(&self.path)?;
+        serde_json::from_str(&contents).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    fn save(&self, movies: &[Movie]) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(movies)?;
+        fs::write(&self.path, json)
+    }
+
+    fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+/// Stores the watchlist as CSV rows (`year,watched,movie,plot,rating`).
+pub struct CsvStore {
+    path: String,
+}
+
+impl CsvStore {
+    pub fn new(path: impl Into<String>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl Store for CsvStore {
+    fn load(&self) -> io::Result<Vec<Movie>> {
+        if !Path::new(&self.path).exists() {
+            return Ok(Vec::new());
+        }
+        let mut reader = csv::Reader::from_path(&self.path)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let mut movies = Vec::new();
+        for record in reader.deserialize() {
+            let movie: Movie = record.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            movies.push(movie);
+        }
+        Ok(movies)
+    }
+
+    fn save(&self, movies: &[Movie]) -> io::Result<()> {
+        let mut writer = csv::Writer::from_path(&self.path)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        for movie in movies {
+            writer
+                .serialize(movie)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        }
+        writer
+            .flush()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    fn path(&self) -> &str {
+        &self.path
+    }
+}